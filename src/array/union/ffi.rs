@@ -6,6 +6,11 @@ use super::super::{ffi::ToFfi, Array};
 use super::UnionArray;
 
 unsafe impl ToFfi for UnionArray {
+    // The exported `types`/`offsets` pointers are always the base of their
+    // buffers (`Buffer::as_ptr` ignores the logical offset); the slice window is
+    // carried separately by `offset`. `FromFfi::try_from_ffi` re-applies that
+    // single `offset` to both buffers, so a sliced dense union round-trips
+    // without any extra handling on the export side.
     fn buffers(&self) -> Vec<Option<std::ptr::NonNull<u8>>> {
         if let Some(offsets) = &self.offsets {
             vec![
@@ -34,7 +39,7 @@ unsafe impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
         let fields = Self::get_fields(field.data_type());
 
         let mut types = unsafe { array.buffer::<i8>(0) }?;
-        let offsets = if Self::is_sparse(&data_type) {
+        let mut offsets = if Self::is_sparse(&data_type) {
             None
         } else {
             Some(unsafe { array.buffer::<i32>(1) }?)
@@ -51,6 +56,9 @@ unsafe impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
 
         if offset > 0 {
             types = types.slice(offset, length);
+            // for dense unions the `offsets` buffer is parallel to `types`, so it
+            // must be advanced by the same amount to keep the child offsets aligned
+            offsets = offsets.map(|offsets| offsets.slice(offset, length));
         };
 
         Ok(Self::from_data(data_type, types, fields, offsets))
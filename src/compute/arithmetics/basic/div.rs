@@ -10,6 +10,7 @@ use crate::{
         arithmetics::{ArrayCheckedDiv, ArrayDiv, NotI128},
         arity::{binary, binary_checked, unary, unary_checked},
     },
+    compute::cast::{cast, CastOptions},
     error::{ArrowError, Result},
     types::NativeType,
 };
@@ -177,6 +178,151 @@ where
                 ))
             }
         }
+        DataType::Int64 => {
+            let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+            let rhs = rhs.to_i64().unwrap();
+
+            if rhs == -1 {
+                // `i64::MIN / -1` overflows; deferring to the naive path keeps
+                // the panic that the element-wise kernel would raise.
+                // Safety: we just proved that `lhs` is `PrimitiveArray<i64>`.
+                return unsafe {
+                    std::mem::transmute::<PrimitiveArray<i64>, PrimitiveArray<T>>(unary(
+                        lhs,
+                        |a| a / rhs,
+                        lhs.data_type().clone(),
+                    ))
+                };
+            }
+
+            // `unsigned_abs` is always representable, even for `i64::MIN`.
+            let reduced_div = StrengthReducedU64::new(rhs.unsigned_abs());
+            let rhs_is_negative = rhs < 0;
+            // Safety: we just proved that `lhs` is `PrimitiveArray<i64>` which means that
+            // T = i64
+            unsafe {
+                std::mem::transmute::<PrimitiveArray<i64>, PrimitiveArray<T>>(unary(
+                    lhs,
+                    |a| {
+                        let quotient = a.unsigned_abs() / reduced_div;
+                        if (a < 0) ^ rhs_is_negative {
+                            quotient.wrapping_neg() as i64
+                        } else {
+                            quotient as i64
+                        }
+                    },
+                    lhs.data_type().clone(),
+                ))
+            }
+        }
+        DataType::Int32 => {
+            let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+            let rhs = rhs.to_i32().unwrap();
+
+            if rhs == -1 {
+                // `i32::MIN / -1` overflows; deferring to the naive path keeps
+                // the panic that the element-wise kernel would raise.
+                // Safety: we just proved that `lhs` is `PrimitiveArray<i32>`.
+                return unsafe {
+                    std::mem::transmute::<PrimitiveArray<i32>, PrimitiveArray<T>>(unary(
+                        lhs,
+                        |a| a / rhs,
+                        lhs.data_type().clone(),
+                    ))
+                };
+            }
+
+            let reduced_div = StrengthReducedU32::new(rhs.unsigned_abs());
+            let rhs_is_negative = rhs < 0;
+            // Safety: we just proved that `lhs` is `PrimitiveArray<i32>` which means that
+            // T = i32
+            unsafe {
+                std::mem::transmute::<PrimitiveArray<i32>, PrimitiveArray<T>>(unary(
+                    lhs,
+                    |a| {
+                        let quotient = a.unsigned_abs() / reduced_div;
+                        if (a < 0) ^ rhs_is_negative {
+                            quotient.wrapping_neg() as i32
+                        } else {
+                            quotient as i32
+                        }
+                    },
+                    lhs.data_type().clone(),
+                ))
+            }
+        }
+        DataType::Int16 => {
+            let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<i16>>().unwrap();
+            let rhs = rhs.to_i16().unwrap();
+
+            if rhs == -1 {
+                // `i16::MIN / -1` overflows; deferring to the naive path keeps
+                // the panic that the element-wise kernel would raise.
+                // Safety: we just proved that `lhs` is `PrimitiveArray<i16>`.
+                return unsafe {
+                    std::mem::transmute::<PrimitiveArray<i16>, PrimitiveArray<T>>(unary(
+                        lhs,
+                        |a| a / rhs,
+                        lhs.data_type().clone(),
+                    ))
+                };
+            }
+
+            let reduced_div = StrengthReducedU16::new(rhs.unsigned_abs());
+            let rhs_is_negative = rhs < 0;
+            // Safety: we just proved that `lhs` is `PrimitiveArray<i16>` which means that
+            // T = i16
+            unsafe {
+                std::mem::transmute::<PrimitiveArray<i16>, PrimitiveArray<T>>(unary(
+                    lhs,
+                    |a| {
+                        let quotient = a.unsigned_abs() / reduced_div;
+                        if (a < 0) ^ rhs_is_negative {
+                            quotient.wrapping_neg() as i16
+                        } else {
+                            quotient as i16
+                        }
+                    },
+                    lhs.data_type().clone(),
+                ))
+            }
+        }
+        DataType::Int8 => {
+            let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<i8>>().unwrap();
+            let rhs = rhs.to_i8().unwrap();
+
+            if rhs == -1 {
+                // `i8::MIN / -1` overflows; deferring to the naive path keeps
+                // the panic that the element-wise kernel would raise.
+                // Safety: we just proved that `lhs` is `PrimitiveArray<i8>`.
+                return unsafe {
+                    std::mem::transmute::<PrimitiveArray<i8>, PrimitiveArray<T>>(unary(
+                        lhs,
+                        |a| a / rhs,
+                        lhs.data_type().clone(),
+                    ))
+                };
+            }
+
+            let reduced_div = StrengthReducedU8::new(rhs.unsigned_abs());
+            let rhs_is_negative = rhs < 0;
+            // Safety: we just proved that `lhs` is `PrimitiveArray<i8>` which means that
+            // T = i8
+            unsafe {
+                std::mem::transmute::<PrimitiveArray<i8>, PrimitiveArray<T>>(unary(
+                    lhs,
+                    |a| {
+                        let quotient = a.unsigned_abs() / reduced_div;
+                        if (a < 0) ^ rhs_is_negative {
+                            quotient.wrapping_neg() as i8
+                        } else {
+                            quotient as i8
+                        }
+                    },
+                    lhs.data_type().clone(),
+                ))
+            }
+        }
         _ => unary(lhs, |a| a / rhs, lhs.data_type().clone()),
     }
 }
@@ -228,6 +374,107 @@ where
     }
 }
 
+// Divides two arrays after upcasting both to the output native type `O`, the
+// standard numeric promotion of the two operands.
+fn div_promoted<O>(lhs: &dyn Array, rhs: &dyn Array) -> Result<PrimitiveArray<O>>
+where
+    O: NativeType + Div<Output = O> + NotI128,
+{
+    let lhs = cast(lhs, &O::DATA_TYPE, CastOptions::default())?;
+    let rhs = cast(rhs, &O::DATA_TYPE, CastOptions::default())?;
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<O>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveArray<O>>().unwrap();
+    div(lhs, rhs)
+}
+
+fn checked_div_promoted<O>(lhs: &dyn Array, rhs: &dyn Array) -> Result<PrimitiveArray<O>>
+where
+    O: NativeType + CheckedDiv<Output = O> + Zero + NotI128,
+{
+    let lhs = cast(lhs, &O::DATA_TYPE, CastOptions::default())?;
+    let rhs = cast(rhs, &O::DATA_TYPE, CastOptions::default())?;
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<O>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveArray<O>>().unwrap();
+    checked_div(lhs, rhs)
+}
+
+// Implements `ArrayDiv` between two different native types, promoting both
+// operands to `$out` before dividing.
+macro_rules! impl_mixed_div {
+    ($lhs:ty, $rhs:ty, $out:ty) => {
+        impl ArrayDiv<PrimitiveArray<$rhs>> for PrimitiveArray<$lhs> {
+            type Output = PrimitiveArray<$out>;
+
+            fn div(&self, rhs: &PrimitiveArray<$rhs>) -> Result<Self::Output> {
+                div_promoted::<$out>(self, rhs)
+            }
+        }
+    };
+}
+
+// Implements `ArrayCheckedDiv` between two different integer native types.
+macro_rules! impl_mixed_checked_div {
+    ($lhs:ty, $rhs:ty, $out:ty) => {
+        impl ArrayCheckedDiv<PrimitiveArray<$rhs>> for PrimitiveArray<$lhs> {
+            type Output = PrimitiveArray<$out>;
+
+            fn checked_div(&self, rhs: &PrimitiveArray<$rhs>) -> Result<Self::Output> {
+                checked_div_promoted::<$out>(self, rhs)
+            }
+        }
+    };
+}
+
+// Integer promotions: the wider of the two signed types wins. Both checked and
+// unchecked variants are available.
+macro_rules! impl_mixed_int {
+    ($lhs:ty, $rhs:ty, $out:ty) => {
+        impl_mixed_div!($lhs, $rhs, $out);
+        impl_mixed_checked_div!($lhs, $rhs, $out);
+    };
+}
+
+impl_mixed_int!(i8, i16, i16);
+impl_mixed_int!(i16, i8, i16);
+impl_mixed_int!(i8, i32, i32);
+impl_mixed_int!(i32, i8, i32);
+impl_mixed_int!(i16, i32, i32);
+impl_mixed_int!(i32, i16, i32);
+impl_mixed_int!(i8, i64, i64);
+impl_mixed_int!(i64, i8, i64);
+impl_mixed_int!(i16, i64, i64);
+impl_mixed_int!(i64, i16, i64);
+impl_mixed_int!(i32, i64, i64);
+impl_mixed_int!(i64, i32, i64);
+
+// Unsigned promotions mirror the signed ones: the wider of the two unsigned
+// types wins. Mixed signed/unsigned pairs are intentionally omitted because the
+// two halves (`u64`/`i64` in particular) have no common native supertype to
+// promote into; callers wanting those must `cast` explicitly first.
+impl_mixed_int!(u8, u16, u16);
+impl_mixed_int!(u16, u8, u16);
+impl_mixed_int!(u8, u32, u32);
+impl_mixed_int!(u32, u8, u32);
+impl_mixed_int!(u16, u32, u32);
+impl_mixed_int!(u32, u16, u32);
+impl_mixed_int!(u8, u64, u64);
+impl_mixed_int!(u64, u8, u64);
+impl_mixed_int!(u16, u64, u64);
+impl_mixed_int!(u64, u16, u64);
+impl_mixed_int!(u32, u64, u64);
+impl_mixed_int!(u64, u32, u64);
+
+// Mixed integer/float and float promotions always widen to the floating type.
+// Floating point has no `CheckedDiv`, so only the unchecked variant exists.
+impl_mixed_div!(i32, f64, f64);
+impl_mixed_div!(f64, i32, f64);
+impl_mixed_div!(i64, f64, f64);
+impl_mixed_div!(f64, i64, f64);
+impl_mixed_div!(i32, f32, f32);
+impl_mixed_div!(f32, i32, f32);
+impl_mixed_div!(f32, f64, f64);
+impl_mixed_div!(f64, f32, f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -313,6 +560,32 @@ mod tests {
         let result = div_scalar(&a, &1u8);
         let expected = UInt8Array::from(&[None, Some(6), None, Some(6)]);
         assert_eq!(result, expected);
+
+        // check the signed strength reduced branches, including the sign and
+        // `MIN` edge cases, against naive truncating division
+        let a = Int32Array::from(&[Some(-7), Some(7), Some(i32::MIN), Some(-8)]);
+        let result = div_scalar(&a, &3i32);
+        let expected = Int32Array::from(&[Some(-2), Some(2), Some(i32::MIN / 3), Some(-2)]);
+        assert_eq!(result, expected);
+
+        let result = div_scalar(&a, &-3i32);
+        let expected = Int32Array::from(&[Some(2), Some(-2), Some(i32::MIN / -3), Some(2)]);
+        assert_eq!(result, expected);
+
+        // divisor of `i8::MIN` (whose magnitude isn't representable as `i8`)
+        let a = Int8Array::from(&[Some(i8::MIN), Some(-128), Some(10)]);
+        let result = div_scalar(&a, &i8::MIN);
+        let expected = Int8Array::from(&[Some(1), Some(1), Some(0)]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_scalar_min_by_minus_one() {
+        // `i8::MIN / -1` overflows; the fast path must preserve the panic that
+        // naive truncating division raises rather than silently wrapping.
+        let a = Int8Array::from(&[Some(i8::MIN)]);
+        div_scalar(&a, &-1i8);
     }
 
     #[test]
@@ -331,4 +604,35 @@ mod tests {
         let result = a.checked_div(&0).unwrap();
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_div_mixed_types() {
+        // Int32 / Int16 promotes to Int32
+        let a = Int32Array::from(&[Some(10), Some(9)]);
+        let b = Int16Array::from(&[Some(5), Some(2)]);
+        let result = a.div(&b).unwrap();
+        let expected = Int32Array::from(&[Some(2), Some(4)]);
+        assert_eq!(result, expected);
+
+        // Float64 / Int32 promotes to Float64
+        let a = Float64Array::from(&[Some(10.0), Some(9.0)]);
+        let b = Int32Array::from(&[Some(5), Some(2)]);
+        let result = a.div(&b).unwrap();
+        let expected = Float64Array::from(&[Some(2.0), Some(4.5)]);
+        assert_eq!(result, expected);
+
+        // Checked variant across integer types
+        let a = Int32Array::from(&[Some(10), None, Some(9)]);
+        let b = Int16Array::from(&[Some(5), Some(2), Some(0)]);
+        let result = a.checked_div(&b).unwrap();
+        let expected = Int32Array::from(&[Some(2), None, None]);
+        assert_eq!(result, expected);
+
+        // UInt32 / UInt16 promotes to UInt32
+        let a = UInt32Array::from(&[Some(10), Some(9)]);
+        let b = UInt16Array::from(&[Some(5), Some(2)]);
+        let result = a.div(&b).unwrap();
+        let expected = UInt32Array::from(&[Some(2), Some(4)]);
+        assert_eq!(result, expected);
+    }
 }
@@ -0,0 +1,4 @@
+//! Contains arithmetic kernels for [`PrimitiveArray`](crate::array::PrimitiveArray)s,
+//! one module per operation.
+pub mod div;
+pub mod rem;
@@ -0,0 +1,64 @@
+//! Defines the arithmetic kernels and the traits used to dispatch them over
+//! [`PrimitiveArray`](crate::array::PrimitiveArray)s.
+pub mod basic;
+
+use crate::error::Result;
+
+/// Marker trait implemented by the integer and floating point
+/// [`NativeType`](crate::types::NativeType)s other than `i128`. It bounds the
+/// blanket trait impls of the kernels in [`basic`] so that they do not cover
+/// `i128`.
+pub trait NotI128 {}
+impl NotI128 for u8 {}
+impl NotI128 for u16 {}
+impl NotI128 for u32 {}
+impl NotI128 for u64 {}
+impl NotI128 for i8 {}
+impl NotI128 for i16 {}
+impl NotI128 for i32 {}
+impl NotI128 for i64 {}
+impl NotI128 for f32 {}
+impl NotI128 for f64 {}
+
+/// Division of two arrays, or of an array by a scalar, selected through the
+/// `Rhs` type parameter.
+pub trait ArrayDiv<Rhs> {
+    /// The type of the resulting array.
+    type Output;
+
+    /// Divides `self` by `rhs`.
+    fn div(&self, rhs: &Rhs) -> Result<Self::Output>;
+}
+
+/// Checked division of two arrays, or of an array by a scalar, selected through
+/// the `Rhs` type parameter. A division that overflows sets the corresponding
+/// validity bit to `None` rather than panicking.
+pub trait ArrayCheckedDiv<Rhs> {
+    /// The type of the resulting array.
+    type Output;
+
+    /// Divides `self` by `rhs`, setting overflowing results to `None`.
+    fn checked_div(&self, rhs: &Rhs) -> Result<Self::Output>;
+}
+
+/// Remainder of two arrays, or of an array by a scalar, selected through the
+/// `Rhs` type parameter.
+pub trait ArrayRem<Rhs> {
+    /// The type of the resulting array.
+    type Output;
+
+    /// Computes the remainder of `self` divided by `rhs`.
+    fn rem(&self, rhs: &Rhs) -> Result<Self::Output>;
+}
+
+/// Checked remainder of two arrays, or of an array by a scalar, selected through
+/// the `Rhs` type parameter. A remainder that overflows sets the corresponding
+/// validity bit to `None` rather than panicking.
+pub trait ArrayCheckedRem<Rhs> {
+    /// The type of the resulting array.
+    type Output;
+
+    /// Computes the remainder of `self` divided by `rhs`, setting overflowing
+    /// results to `None`.
+    fn checked_rem(&self, rhs: &Rhs) -> Result<Self::Output>;
+}
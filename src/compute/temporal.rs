@@ -17,101 +17,256 @@
 
 //! Defines temporal kernels for time and date related functions.
 
-use chrono::{Datelike, Timelike};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDateTime, TimeZone, Timelike};
 
 use crate::array::*;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::temporal_conversions::*;
+use crate::types::NativeType;
 
 use super::arity::unary;
 
-/// Extracts the hours of a given temporal array as an array of integers
-pub fn hour(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
-    let final_data_type = DataType::UInt32;
+/// Parses a fixed `[-]HH:MM` offset, used as a fallback when a timezone string
+/// is numeric rather than an IANA name.
+fn parse_offset(offset: &str) -> Result<FixedOffset> {
+    let error = "timezone offset must be of the form [-]HH:MM";
+    // The sign belongs to the whole offset, so parse it once from the string
+    // rather than deriving it from `hours.signum()` (which is `0` for `-00:30`).
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    let mut parts = offset
+        .trim_start_matches(|c| c == '+' || c == '-')
+        .splitn(2, ':');
+    let hours: i32 = parts
+        .next()
+        .ok_or_else(|| ArrowError::InvalidArgumentError(error.to_string()))?
+        .parse()
+        .map_err(|_| ArrowError::InvalidArgumentError(error.to_string()))?;
+    let minutes: i32 = parts
+        .next()
+        .ok_or_else(|| ArrowError::InvalidArgumentError(error.to_string()))?
+        .parse()
+        .map_err(|_| ArrowError::InvalidArgumentError(error.to_string()))?;
+    Ok(FixedOffset::east(sign * (hours * 60 * 60 + minutes * 60)))
+}
+
+/// Localizes every raw timestamp of `array` into `timezone` and extracts a field
+/// from the resulting local `NaiveDateTime`.
+fn extract_impl<T, O, F>(
+    array: &PrimitiveArray<i64>,
+    time_unit: &TimeUnit,
+    timezone: T,
+    extract: F,
+    data_type: DataType,
+) -> PrimitiveArray<O>
+where
+    T: TimeZone,
+    O: NativeType,
+    F: Fn(NaiveDateTime) -> O,
+{
+    let op = |x| {
+        let datetime = match time_unit {
+            TimeUnit::Second => timestamp_s_to_datetime(x),
+            TimeUnit::Millisecond => timestamp_ms_to_datetime(x),
+            TimeUnit::Microsecond => timestamp_us_to_datetime(x),
+            TimeUnit::Nanosecond => timestamp_ns_to_datetime(x),
+        };
+        let offset = timezone.offset_from_utc_datetime(&datetime);
+        extract(DateTime::<T>::from_utc(datetime, offset).naive_local())
+    };
+    unary(array, op, data_type)
+}
+
+/// Extracts a field from a timezone-carrying timestamp array. With the
+/// `chrono-tz` feature enabled the timezone is parsed as an IANA name, falling
+/// back to a fixed `[-]HH:MM` offset when the string is numeric; without it,
+/// only fixed offsets are accepted.
+fn extract_timezone<O, F>(
+    array: &PrimitiveArray<i64>,
+    time_unit: &TimeUnit,
+    timezone: &str,
+    extract: F,
+    data_type: DataType,
+) -> Result<PrimitiveArray<O>>
+where
+    O: NativeType,
+    F: Fn(NaiveDateTime) -> O,
+{
+    #[cfg(feature = "chrono-tz")]
+    if let Ok(timezone) = timezone.parse::<chrono_tz::Tz>() {
+        return Ok(extract_impl(array, time_unit, timezone, extract, data_type));
+    }
+    let timezone = parse_offset(timezone)?;
+    Ok(extract_impl(array, time_unit, timezone, extract, data_type))
+}
+
+/// Maps a date-like array (`Date32`, `Date64` or any `Timestamp`) to the
+/// `NaiveDateTime` of every element and applies `extract` to it. This is the
+/// single place each `Datelike` kernel funnels through, so adding one is just a
+/// matter of choosing an accessor.
+fn date_variants<O, F>(
+    array: &dyn Array,
+    data_type: DataType,
+    extract: F,
+) -> Result<PrimitiveArray<O>>
+where
+    O: NativeType,
+    F: Fn(NaiveDateTime) -> O,
+{
     match array.data_type() {
-        DataType::Time32(TimeUnit::Second) => {
+        DataType::Date32 => {
             let array = array
                 .as_any()
                 .downcast_ref::<PrimitiveArray<i32>>()
                 .unwrap();
-            Ok(unary(array, |x| time32s_to_time(x).hour(), final_data_type))
+            Ok(unary(array, |x| extract(date32_to_datetime(x)), data_type))
         }
-        DataType::Time32(TimeUnit::Microsecond) => {
+        DataType::Date64 => {
             let array = array
                 .as_any()
-                .downcast_ref::<PrimitiveArray<i32>>()
+                .downcast_ref::<PrimitiveArray<i64>>()
                 .unwrap();
-            Ok(unary(
-                array,
-                |x| time32ms_to_time(x).hour(),
-                final_data_type,
-            ))
+            Ok(unary(array, |x| extract(date64_to_datetime(x)), data_type))
         }
-        DataType::Time64(TimeUnit::Microsecond) => {
+        DataType::Timestamp(time_unit, None) => {
             let array = array
                 .as_any()
                 .downcast_ref::<PrimitiveArray<i64>>()
                 .unwrap();
-            Ok(unary(
-                array,
-                |x| time64us_to_time(x).hour(),
-                final_data_type,
-            ))
+            Ok(match time_unit {
+                TimeUnit::Second => unary(array, |x| extract(timestamp_s_to_datetime(x)), data_type),
+                TimeUnit::Millisecond => {
+                    unary(array, |x| extract(timestamp_ms_to_datetime(x)), data_type)
+                }
+                TimeUnit::Microsecond => {
+                    unary(array, |x| extract(timestamp_us_to_datetime(x)), data_type)
+                }
+                TimeUnit::Nanosecond => {
+                    unary(array, |x| extract(timestamp_ns_to_datetime(x)), data_type)
+                }
+            })
         }
-        DataType::Time64(TimeUnit::Nanosecond) => {
+        DataType::Timestamp(time_unit, Some(timezone)) => {
             let array = array
                 .as_any()
                 .downcast_ref::<PrimitiveArray<i64>>()
                 .unwrap();
-            Ok(unary(
-                array,
-                |x| time64ns_to_time(x).hour(),
-                final_data_type,
-            ))
+            extract_timezone(array, time_unit, timezone, extract, data_type)
         }
-        DataType::Date32 => {
+        dt => Err(ArrowError::NotYetImplemented(format!(
+            "Temporal date extraction does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Maps a time-like array (`Time32`, `Time64`, `Date32`, `Date64` or any
+/// `Timestamp`) to the `NaiveTime`/`NaiveDateTime` of every element and applies
+/// `extract` to it. This is the single place each `Timelike` kernel funnels
+/// through.
+fn time_variants<O, F>(
+    array: &dyn Array,
+    data_type: DataType,
+    extract: F,
+) -> Result<PrimitiveArray<O>>
+where
+    O: NativeType,
+    F: Fn(&dyn Timelike) -> O,
+{
+    match array.data_type() {
+        DataType::Time32(TimeUnit::Second) => {
             let array = array
                 .as_any()
                 .downcast_ref::<PrimitiveArray<i32>>()
                 .unwrap();
-            Ok(unary(
-                array,
-                |x| date32_to_datetime(x).hour(),
-                final_data_type,
-            ))
+            Ok(unary(array, |x| extract(&time32s_to_time(x)), data_type))
         }
-        DataType::Date64 => {
+        DataType::Time32(TimeUnit::Microsecond) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            Ok(unary(array, |x| extract(&time32ms_to_time(x)), data_type))
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
             let array = array
                 .as_any()
                 .downcast_ref::<PrimitiveArray<i64>>()
                 .unwrap();
-            Ok(unary(
-                array,
-                |x| date64_to_datetime(x).hour(),
-                final_data_type,
-            ))
+            Ok(unary(array, |x| extract(&time64us_to_time(x)), data_type))
         }
-        DataType::Timestamp(time_unit, None) => {
+        DataType::Time64(TimeUnit::Nanosecond) => {
             let array = array
                 .as_any()
                 .downcast_ref::<PrimitiveArray<i64>>()
                 .unwrap();
-            let op = match time_unit {
-                TimeUnit::Second => |x| timestamp_s_to_datetime(x).hour(),
-                TimeUnit::Millisecond => |x| timestamp_ms_to_datetime(x).hour(),
-                TimeUnit::Microsecond => |x| timestamp_us_to_datetime(x).hour(),
-                TimeUnit::Nanosecond => |x| timestamp_ns_to_datetime(x).hour(),
-            };
-            Ok(unary(array, op, final_data_type))
+            Ok(unary(array, |x| extract(&time64ns_to_time(x)), data_type))
         }
-        dt => Err(ArrowError::NotYetImplemented(format!(
-            "\"hour\" does not support type {:?}",
-            dt
-        ))),
+        _ => date_variants(array, data_type, |x| extract(&x)),
     }
 }
 
+/// Extracts the hours of a given temporal array as an array of integers
+pub fn hour(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    time_variants(array, DataType::UInt32, |x| x.hour())
+}
+
+/// Extracts the minutes of a given temporal array as an array of integers
+pub fn minute(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    time_variants(array, DataType::UInt32, |x| x.minute())
+}
+
+/// Extracts the seconds of a given temporal array as an array of integers
+pub fn second(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    time_variants(array, DataType::UInt32, |x| x.second())
+}
+
+/// Extracts the nanoseconds of a given temporal array as an array of integers
+pub fn nanosecond(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    time_variants(array, DataType::UInt32, |x| x.nanosecond())
+}
+
+/// Extracts the years of a given temporal array as an array of integers
+pub fn year(array: &dyn Array) -> Result<PrimitiveArray<i32>> {
+    date_variants(array, DataType::Int32, |x| x.year())
+}
+
+/// Extracts the months of a given temporal array as an array of integers.
+/// Value ranges from 1 to 12.
+pub fn month(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_variants(array, DataType::UInt32, |x| x.month())
+}
+
+/// Extracts the days of a given temporal array as an array of integers.
+/// Value ranges from 1 to 31 (Last day depends on month).
+pub fn day(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_variants(array, DataType::UInt32, |x| x.day())
+}
+
+/// Extracts weekday of a given temporal array as an array of integers.
+/// Monday is 1, Tuesday is 2, ..., Sunday is 7.
+pub fn weekday(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_variants(array, DataType::UInt32, |x| x.weekday().number_from_monday())
+}
+
+/// Extracts the day of year of a given temporal array as an array of integers.
+/// Value ranges from 1 to 366 (leap year).
+pub fn ordinal(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_variants(array, DataType::UInt32, |x| x.ordinal())
+}
+
+/// Extracts the ISO week of a given temporal array as an array of integers.
+/// Value ranges from 1 to 53 (Last week depends on the year).
+pub fn iso_week(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_variants(array, DataType::UInt32, |x| x.iso_week().week())
+}
+
+/// Extracts the quarter of a given temporal array as an array of integers.
+/// Value ranges from 1 to 4.
+pub fn quarter(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_variants(array, DataType::UInt32, |x| (x.month() - 1) / 3 + 1)
+}
+
 /// Checks if an array of type `datatype` can perform hour operation
 ///
 /// # Examples
@@ -126,6 +281,26 @@ pub fn hour(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
 /// assert_eq!(can_hour(&data_type), false);
 /// ```
 pub fn can_hour(data_type: &DataType) -> bool {
+    can_time(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform minute operation
+pub fn can_minute(data_type: &DataType) -> bool {
+    can_time(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform second operation
+pub fn can_second(data_type: &DataType) -> bool {
+    can_time(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform nanosecond operation
+pub fn can_nanosecond(data_type: &DataType) -> bool {
+    can_time(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform a `Timelike` operation
+fn can_time(data_type: &DataType) -> bool {
     matches!(
         data_type,
         DataType::Time32(TimeUnit::Second)
@@ -134,56 +309,10 @@ pub fn can_hour(data_type: &DataType) -> bool {
             | DataType::Time64(TimeUnit::Nanosecond)
             | DataType::Date32
             | DataType::Date64
-            | DataType::Timestamp(_, None)
+            | DataType::Timestamp(_, _)
     )
 }
 
-/// Extracts the hours of a given temporal array as an array of integers
-pub fn year(array: &dyn Array) -> Result<PrimitiveArray<i32>> {
-    let final_data_type = DataType::Int32;
-    match array.data_type() {
-        DataType::Date32 => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i32>>()
-                .unwrap();
-            Ok(unary(
-                array,
-                |x| date32_to_datetime(x).year(),
-                final_data_type,
-            ))
-        }
-        DataType::Date64 => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i64>>()
-                .unwrap();
-            Ok(unary(
-                array,
-                |x| date64_to_datetime(x).year(),
-                final_data_type,
-            ))
-        }
-        DataType::Timestamp(time_unit, None) => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i64>>()
-                .unwrap();
-            let op = match time_unit {
-                TimeUnit::Second => |x| timestamp_s_to_datetime(x).year(),
-                TimeUnit::Millisecond => |x| timestamp_ms_to_datetime(x).year(),
-                TimeUnit::Microsecond => |x| timestamp_us_to_datetime(x).year(),
-                TimeUnit::Nanosecond => |x| timestamp_ns_to_datetime(x).year(),
-            };
-            Ok(unary(array, op, final_data_type))
-        }
-        dt => Err(ArrowError::NotYetImplemented(format!(
-            "\"year\" does not support type {:?}",
-            dt
-        ))),
-    }
-}
-
 /// Checks if an array of type `datatype` can perform year operation
 ///
 /// # Examples
@@ -198,8 +327,98 @@ pub fn year(array: &dyn Array) -> Result<PrimitiveArray<i32>> {
 /// assert_eq!(can_year(&data_type), false);
 /// ```
 pub fn can_year(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform month operation
+pub fn can_month(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform day operation
+pub fn can_day(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform weekday operation
+pub fn can_weekday(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform ordinal operation
+pub fn can_ordinal(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform iso_week operation
+pub fn can_iso_week(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform quarter operation
+pub fn can_quarter(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform a `Datelike` operation
+fn can_date(data_type: &DataType) -> bool {
     matches!(
         data_type,
-        DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, None)
+        DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _)
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_offset() {
+        assert_eq!(parse_offset("+05:30").unwrap(), FixedOffset::east(5 * 3600 + 1800));
+        assert_eq!(parse_offset("-05:30").unwrap(), FixedOffset::east(-(5 * 3600 + 1800)));
+        // the sign must survive a zero hour field
+        assert_eq!(parse_offset("-00:30").unwrap(), FixedOffset::east(-1800));
+        assert_eq!(parse_offset("+00:30").unwrap(), FixedOffset::east(1800));
+    }
+
+    #[test]
+    fn test_hour_timezone_dst() {
+        // `America/New_York` is EDT (UTC-4) in July and EST (UTC-5) in January,
+        // so the same kernel must return a different local hour across the
+        // daylight-saving boundary.
+        let data_type = DataType::Timestamp(TimeUnit::Second, Some("America/New_York".to_string()));
+
+        // 2021-07-01T00:00:00Z -> 2021-06-30T20:00:00 EDT
+        let summer = Int64Array::from(&[Some(1625097600)]).to(data_type.clone());
+        assert_eq!(hour(&summer).unwrap(), UInt32Array::from(&[Some(20)]));
+
+        // 2021-01-01T00:00:00Z -> 2020-12-31T19:00:00 EST
+        let winter = Int64Array::from(&[Some(1609459200)]).to(data_type);
+        assert_eq!(hour(&winter).unwrap(), UInt32Array::from(&[Some(19)]));
+    }
+
+    #[test]
+    fn test_date_kernels() {
+        // 2021-03-09T00:00:00Z is a Tuesday, day-of-year 68, ISO week 10.
+        let array = Int64Array::from(&[Some(1615248000)])
+            .to(DataType::Timestamp(TimeUnit::Second, None));
+        assert_eq!(year(&array).unwrap(), Int32Array::from(&[Some(2021)]));
+        assert_eq!(month(&array).unwrap(), UInt32Array::from(&[Some(3)]));
+        assert_eq!(day(&array).unwrap(), UInt32Array::from(&[Some(9)]));
+        assert_eq!(weekday(&array).unwrap(), UInt32Array::from(&[Some(2)]));
+        assert_eq!(ordinal(&array).unwrap(), UInt32Array::from(&[Some(68)]));
+        assert_eq!(iso_week(&array).unwrap(), UInt32Array::from(&[Some(10)]));
+        assert_eq!(quarter(&array).unwrap(), UInt32Array::from(&[Some(1)]));
+    }
+
+    #[test]
+    fn test_time_kernels() {
+        // 2021-03-09T13:42:09Z.
+        let array = Int64Array::from(&[Some(1615297329)])
+            .to(DataType::Timestamp(TimeUnit::Second, None));
+        assert_eq!(hour(&array).unwrap(), UInt32Array::from(&[Some(13)]));
+        assert_eq!(minute(&array).unwrap(), UInt32Array::from(&[Some(42)]));
+        assert_eq!(second(&array).unwrap(), UInt32Array::from(&[Some(9)]));
+        assert_eq!(nanosecond(&array).unwrap(), UInt32Array::from(&[Some(0)]));
+    }
+}